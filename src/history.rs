@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use chrono::{DateTime, Local, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A single completed tracking session for one process.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionRecord {
+    pub process_name: String,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub active_secs: u64,
+}
+
+/// Every completed session, persisted as JSON under the OS config directory
+/// so lifetime and daily totals survive restarts.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct HistoryStore {
+    sessions: Vec<SessionRecord>,
+}
+
+impl HistoryStore {
+    fn store_path() -> Option<PathBuf> {
+        let mut path = dirs::config_dir()?;
+        path.push("TimeTracker");
+        path.push("history.json");
+        Some(path)
+    }
+
+    /// Loads the store from disk, falling back to an empty store if it is
+    /// missing, unreadable, or corrupt.
+    pub fn load() -> Self {
+        Self::store_path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    fn save(&self) {
+        let Some(path) = Self::store_path() else {
+            return;
+        };
+        if let Some(parent) = path.parent() {
+            if let Err(e) = fs::create_dir_all(parent) {
+                eprintln!("Failed to create history directory: {}", e);
+                return;
+            }
+        }
+        match serde_json::to_string_pretty(self) {
+            Ok(json) => {
+                if let Err(e) = fs::write(path, json) {
+                    eprintln!("Failed to write history: {}", e);
+                }
+            }
+            Err(e) => eprintln!("Failed to serialize history: {}", e),
+        }
+    }
+
+    /// Appends a finished session and persists the store immediately, so
+    /// nothing is lost if the app is killed right after.
+    pub fn record_session(
+        &mut self,
+        process_name: String,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        active_secs: u64,
+    ) {
+        self.sessions.push(SessionRecord {
+            process_name,
+            start,
+            end,
+            active_secs,
+        });
+        self.save();
+    }
+
+    /// Maps each tracked executable name to its `(today, lifetime)` active
+    /// seconds, where "today" is based on the session's local end time.
+    pub fn totals_by_name(&self) -> HashMap<String, (u64, u64)> {
+        let today = Local::now().date_naive();
+        let mut totals: HashMap<String, (u64, u64)> = HashMap::new();
+
+        for session in &self.sessions {
+            let entry = totals.entry(session.process_name.clone()).or_default();
+            entry.1 += session.active_secs;
+            if session.end.with_timezone(&Local).date_naive() == today {
+                entry.0 += session.active_secs;
+            }
+        }
+
+        totals
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration as ChronoDuration;
+
+    use super::*;
+
+    #[test]
+    fn totals_by_name_splits_today_from_lifetime() {
+        let now = Utc::now();
+        let yesterday = now - ChronoDuration::days(1);
+
+        let store = HistoryStore {
+            sessions: vec![
+                SessionRecord {
+                    process_name: "editor".to_string(),
+                    start: now,
+                    end: now,
+                    active_secs: 60,
+                },
+                SessionRecord {
+                    process_name: "editor".to_string(),
+                    start: yesterday,
+                    end: yesterday,
+                    active_secs: 30,
+                },
+            ],
+        };
+
+        let totals = store.totals_by_name();
+        assert_eq!(totals.get("editor"), Some(&(60, 90)));
+    }
+}
@@ -1,48 +1,132 @@
 #![windows_subsystem = "windows"]
 
+mod history;
+mod pomodoro;
+
+use clap::Parser;
 use eframe;
 use eframe::egui;
-use eframe::egui::{Context, ViewportCommand};
+use eframe::egui::Context;
+use egui_plot::{Line, Plot, PlotPoints};
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::fmt::Write;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, RwLock};
 
+use chrono::{DateTime, Utc};
 use sysinfo::{Pid, ProcessStatus, ProcessesToUpdate, System};
 use tokio::runtime::Runtime;
 use tokio::select;
+use tokio::sync::oneshot;
 use tokio::time::{interval, Duration, Instant};
 
 use tokio_util::sync::CancellationToken;
 
+use history::HistoryStore;
+use pomodoro::{PomodoroConfig, PomodoroHandle};
+
 const REFRESH_INTERVAL: Duration = Duration::from_millis(200);
 
+/// Startup options for scripted or shortcut-launched use, parsed from the
+/// command line.
+#[derive(Parser, Debug)]
+#[command(version, about = "Lightweight per-process time tracker", long_about = None)]
+struct Cli {
+    /// Override the watcher refresh interval, in milliseconds. Must be at
+    /// least 1, since `tokio::time::interval` panics on a zero period.
+    #[arg(long, value_parser = clap::value_parser!(u64).range(1..))]
+    refresh_ms: Option<u64>,
+
+    /// Auto-select and start tracking the first process whose name contains this string.
+    #[arg(long)]
+    track: Option<String>,
+
+    /// Start the auto-selected watcher (see `--track`) paused.
+    #[arg(long)]
+    start_paused: bool,
+}
+
+/// Resolved startup configuration threaded into `ProcessApp::new`, so the
+/// app never has to fall back on hard-coded defaults.
+struct StartupConfig {
+    refresh_interval: Duration,
+    track: Option<String>,
+    start_paused: bool,
+}
+
+impl From<Cli> for StartupConfig {
+    fn from(cli: Cli) -> Self {
+        StartupConfig {
+            refresh_interval: cli
+                .refresh_ms
+                .map(Duration::from_millis)
+                .unwrap_or(REFRESH_INTERVAL),
+            track: cli.track,
+            start_paused: cli.start_paused,
+        }
+    }
+}
+
+/// How long to keep waiting for a same-named process to reappear before
+/// giving up on a watcher whose tracked PID has disappeared.
+const RESTART_GRACE_PERIOD: Duration = Duration::from_secs(5);
+/// Caps memory use of the per-process resource history: oldest samples are
+/// dropped once a watcher's ring buffer hits this length.
+const SAMPLE_HISTORY_CAP: usize = 1800;
+
+/// One CPU/memory reading taken while a process was tracked.
+#[derive(Debug, Clone, Copy)]
+struct ResourceSample {
+    elapsed_secs: f64,
+    cpu_percent: f32,
+    memory_bytes: u64,
+}
+
 async fn process_watcher_async(
-    pid: Pid,
+    mut pid: Pid,
+    name: String,
     paused: Arc<AtomicBool>,
     duration_text: Arc<RwLock<String>>,
+    samples: Arc<RwLock<VecDeque<ResourceSample>>>,
+    claimed_pids: Arc<RwLock<HashSet<Pid>>>,
     cancellation_token: CancellationToken,
+    result_tx: oneshot::Sender<Duration>,
     ctx: Context,
+    refresh_interval: Duration,
 ) {
     let mut system = System::new_all();
     let mut last_update = Instant::now();
     let mut last_seconds: u64 = 0;
+    let mut missing_since: Option<Instant> = None;
 
     let mut process_duration = Duration::default();
-    let mut update_interval = interval(REFRESH_INTERVAL);
+    let mut update_interval = interval(refresh_interval);
 
     loop {
         select! {
             biased;
             _ = cancellation_token.cancelled() => {
-                return;
+                break;
             },
             _ = update_interval.tick() => {
                 if !paused.load(Ordering::Relaxed) {
                     system.refresh_processes(ProcessesToUpdate::Some(&[pid]), true);
 
                     if let Some(process) = system.process(pid) {
+                        missing_since = None;
+
+                        if let Ok(mut samples) = samples.write() {
+                            samples.push_back(ResourceSample {
+                                elapsed_secs: process_duration.as_secs_f64(),
+                                cpu_percent: process.cpu_usage(),
+                                memory_bytes: process.memory(),
+                            });
+                            while samples.len() > SAMPLE_HISTORY_CAP {
+                                samples.pop_front();
+                            }
+                        }
+
                         if process.status() == ProcessStatus::Run {
                             let now = Instant::now();
                             process_duration += now.saturating_duration_since(last_update);
@@ -67,26 +151,160 @@ async fn process_watcher_async(
                             last_seconds = total_secs;
                         }
                     } else {
-                        return; // Exit the loop if the process no longer exists
+                        // The PID is gone; look for a relaunched process with the
+                        // same executable name before giving up on this session.
+                        // Processes already claimed by another tracker are excluded
+                        // so a watcher never steals a sibling instance of a
+                        // multi-process executable (browsers, Electron apps, etc.).
+                        system.refresh_processes(ProcessesToUpdate::All, true);
+                        let already_claimed = claimed_pids.read().ok();
+                        let relaunched = system.processes().iter().find_map(|(&candidate_pid, process)| {
+                            if already_claimed
+                                .as_ref()
+                                .is_some_and(|claimed| claimed.contains(&candidate_pid))
+                            {
+                                return None;
+                            }
+                            (process.name().to_str() == Some(name.as_str())).then_some(candidate_pid)
+                        });
+                        drop(already_claimed);
+
+                        if let Some(new_pid) = relaunched {
+                            if let Ok(mut claimed) = claimed_pids.write() {
+                                claimed.remove(&pid);
+                                claimed.insert(new_pid);
+                            }
+                            pid = new_pid;
+                            missing_since = None;
+                            last_update = Instant::now();
+                        } else {
+                            let first_missed = *missing_since.get_or_insert_with(Instant::now);
+                            if first_missed.elapsed() >= RESTART_GRACE_PERIOD {
+                                break;
+                            }
+                        }
                     }
                 } else {
-                    // Tracking is paused, reset the last update to avoid accumulating paused time
+                    // Tracking is paused, reset the last update to avoid accumulating paused time.
                     last_update = Instant::now();
+                    // Also reset the restart grace period: a pause shouldn't count as
+                    // time spent waiting for a relaunch, or resuming after a long
+                    // pause would immediately give up on the watcher.
+                    missing_since = None;
                 }
             }
         }
     }
+
+    if let Ok(mut claimed) = claimed_pids.write() {
+        claimed.remove(&pid);
+    }
+
+    // Report what we accumulated so far so the caller can persist it, even
+    // though the watcher stopped before the process itself exited.
+    let _ = result_tx.send(process_duration);
 }
 
-struct ProcessApp {
+/// Everything the UI needs to render and control a single tracked process.
+///
+/// Each handle owns its watcher's shared state independently, so pausing or
+/// stopping one tracked process never touches another.
+struct TrackerHandle {
+    name: String,
+    start: DateTime<Utc>,
     duration_text: Arc<RwLock<String>>,
     paused: Arc<AtomicBool>,
-    cancellation_token: Option<CancellationToken>,
+    samples: Arc<RwLock<VecDeque<ResourceSample>>>,
+    cancellation_token: CancellationToken,
+    result_rx: oneshot::Receiver<Duration>,
+}
+
+impl TrackerHandle {
+    fn spawn(
+        pid: Pid,
+        name: String,
+        ctx: &Context,
+        refresh_interval: Duration,
+        claimed_pids: Arc<RwLock<HashSet<Pid>>>,
+    ) -> Self {
+        let duration_text = Arc::new(RwLock::new(String::from("--:--:--")));
+        let paused = Arc::new(AtomicBool::new(false));
+        let samples = Arc::new(RwLock::new(VecDeque::new()));
+        let cancellation_token = CancellationToken::new();
+        let (result_tx, result_rx) = oneshot::channel();
+
+        // Claim the PID up front so another watcher's relaunch search can
+        // never race with this handle's own spawn.
+        if let Ok(mut claimed) = claimed_pids.write() {
+            claimed.insert(pid);
+        }
+
+        let watcher_paused = paused.clone();
+        let watcher_duration = duration_text.clone();
+        let watcher_samples = samples.clone();
+        let watcher_claimed = claimed_pids.clone();
+        let watcher_token = cancellation_token.clone();
+        let watcher_ctx = ctx.clone();
+
+        let watcher_name = name.clone();
+        tokio::spawn(async move {
+            process_watcher_async(
+                pid,
+                watcher_name,
+                watcher_paused,
+                watcher_duration,
+                watcher_samples,
+                watcher_claimed,
+                watcher_token,
+                result_tx,
+                watcher_ctx,
+                refresh_interval,
+            )
+            .await;
+        });
+
+        TrackerHandle {
+            name,
+            start: Utc::now(),
+            duration_text,
+            paused,
+            samples,
+            cancellation_token,
+            result_rx,
+        }
+    }
 
-    tracked_process: Option<Pid>,
-    tracked_process_name: String,
+    fn stop(&self) {
+        self.cancellation_token.cancel();
+    }
+}
+
+struct ProcessApp {
+    tracked_processes: HashMap<Pid, TrackerHandle>,
+    /// Pause flags of every tracked watcher, kept in sync with
+    /// `tracked_processes` so the Pomodoro task can pause/resume them all
+    /// without needing to know about process tracking directly.
+    paused_flags: Arc<RwLock<Vec<Arc<AtomicBool>>>>,
+    /// PIDs currently bound to a `TrackerHandle`, shared with every watcher
+    /// so a restart-relaunch search can't adopt a process another handle
+    /// already owns.
+    claimed_pids: Arc<RwLock<HashSet<Pid>>>,
+    refresh_interval: Duration,
     system: System,
 
+    history: HistoryStore,
+    history_window_open: bool,
+
+    pomodoro: Option<PomodoroHandle>,
+    pomodoro_window_open: bool,
+    pomodoro_work_minutes: u32,
+    pomodoro_short_break_minutes: u32,
+    pomodoro_long_break_minutes: u32,
+    pomodoro_cycles_before_long_break: u32,
+
+    resource_window_open: bool,
+    resource_chart_pid: Option<Pid>,
+
     process_window_open: bool,
     process_filter: String,
     filtered_processes: HashMap<Pid, String>,
@@ -94,32 +312,99 @@ struct ProcessApp {
 
 impl Drop for ProcessApp {
     fn drop(&mut self) {
-        if let Some(token) = &self.cancellation_token {
-            token.cancel();
+        if let Some(pomodoro) = self.pomodoro.take() {
+            pomodoro.stop();
+        }
+
+        for (_, handle) in self.tracked_processes.drain() {
+            handle.stop();
+
+            // The watcher reacts to cancellation almost immediately; give it
+            // a brief chance to report its final duration so the session
+            // isn't lost when the app closes.
+            let mut result_rx = handle.result_rx;
+            let active_secs = (0..50)
+                .find_map(|_| match result_rx.try_recv() {
+                    Ok(duration) => Some(duration.as_secs()),
+                    Err(oneshot::error::TryRecvError::Closed) => Some(0),
+                    Err(oneshot::error::TryRecvError::Empty) => {
+                        std::thread::sleep(std::time::Duration::from_millis(10));
+                        None
+                    }
+                })
+                .unwrap_or(0);
+
+            self.history
+                .record_session(handle.name, handle.start, Utc::now(), active_secs);
         }
     }
 }
 
 impl ProcessApp {
-    fn new(_cc: &eframe::CreationContext<'_>) -> Self {
-        ProcessApp {
-            duration_text: Arc::new(RwLock::new(String::from("--:--:--"))),
-            paused: Arc::new(AtomicBool::new(false)),
-            cancellation_token: None,
-
-            tracked_process: None,
-            tracked_process_name: String::default(),
-            process_window_open: false,
+    fn new(cc: &eframe::CreationContext<'_>, startup: StartupConfig) -> Self {
+        let default_config = PomodoroConfig::default();
+
+        let mut app = ProcessApp {
+            tracked_processes: HashMap::default(),
+            paused_flags: Arc::new(RwLock::new(Vec::new())),
+            claimed_pids: Arc::new(RwLock::new(HashSet::new())),
+            refresh_interval: startup.refresh_interval,
             system: System::new_all(),
 
+            history: HistoryStore::load(),
+            history_window_open: false,
+
+            pomodoro: None,
+            pomodoro_window_open: false,
+            pomodoro_work_minutes: (default_config.work.as_secs() / 60) as u32,
+            pomodoro_short_break_minutes: (default_config.short_break.as_secs() / 60) as u32,
+            pomodoro_long_break_minutes: (default_config.long_break.as_secs() / 60) as u32,
+            pomodoro_cycles_before_long_break: default_config.cycles_before_long_break,
+
+            resource_window_open: false,
+            resource_chart_pid: None,
+
+            process_window_open: false,
             process_filter: String::default(),
             filtered_processes: HashMap::default(),
+        };
+
+        if let Some(track_name) = startup.track {
+            app.system.refresh_processes(ProcessesToUpdate::All, true);
+            app.process_filter = track_name;
+            app.filter_processes();
+
+            if let Some((&pid, name)) = app.filtered_processes.iter().next() {
+                let handle = TrackerHandle::spawn(
+                    pid,
+                    name.clone(),
+                    &cc.egui_ctx,
+                    app.refresh_interval,
+                    app.claimed_pids.clone(),
+                );
+                if startup.start_paused {
+                    handle.paused.store(true, Ordering::Release);
+                }
+                app.tracked_processes.insert(pid, handle);
+                app.sync_paused_flags();
+            }
+
+            app.process_filter.clear();
+            app.filtered_processes.clear();
         }
+
+        app
     }
 
-    fn stop_and_join_thread(&mut self) {
-        if let Some(token) = self.cancellation_token.take() {
-            token.cancel();
+    /// Rebuilds the shared pause-flag list from the currently tracked
+    /// processes. Call after any insert/remove into `tracked_processes`.
+    fn sync_paused_flags(&self) {
+        if let Ok(mut flags) = self.paused_flags.write() {
+            *flags = self
+                .tracked_processes
+                .values()
+                .map(|handle| handle.paused.clone())
+                .collect();
         }
     }
 
@@ -169,35 +454,23 @@ impl ProcessApp {
                 // List of processes
                 for (&pid, process) in self.filtered_processes.iter_mut() {
                     if ui.selectable_label(false, process.as_str()).clicked() {
-                        if self.tracked_process != Some(pid) {
-                            if let Some(token) = &self.cancellation_token.take() {
-                                token.cancel();
+                        if !self.tracked_processes.contains_key(&pid) {
+                            let handle = TrackerHandle::spawn(
+                                pid,
+                                process.clone(),
+                                ctx,
+                                self.refresh_interval,
+                                self.claimed_pids.clone(),
+                            );
+                            // A Pomodoro break already in progress should apply to a
+                            // newly tracked process immediately, not just at the next
+                            // phase transition.
+                            if self.pomodoro.as_ref().is_some_and(|p| p.is_break()) {
+                                handle.paused.store(true, Ordering::Release);
                             }
-                            self.paused.store(false, Ordering::Release);
-
-                            self.tracked_process = Some(pid);
-                            self.tracked_process_name = process.clone();
+                            self.tracked_processes.insert(pid, handle);
+                            self.sync_paused_flags();
                             should_close_window = true;
-
-                            ctx.send_viewport_cmd(ViewportCommand::Title(format!(
-                                "Time Tracker - {}",
-                                process
-                            )));
-
-                            // Cloning values to move into the new thread safely
-                            let paused = self.paused.clone();
-                            let duration = self.duration_text.clone();
-                            let context = ctx.clone();
-
-                            let cancellation_token = CancellationToken::new();
-                            let token_clone = cancellation_token.clone();
-                            self.cancellation_token = Some(cancellation_token);
-
-                            tokio::spawn(async move {
-                                process_watcher_async(pid, paused, duration, token_clone, context)
-                                    .await;
-                            });
-
                             break;
                         }
                     }
@@ -209,52 +482,341 @@ impl ProcessApp {
             self.process_window_open = false;
         }
     }
+
+    fn open_history_window(&mut self, ctx: &Context) {
+        let mut is_window_open = self.history_window_open;
+
+        egui::Window::new("History")
+            .title_bar(true)
+            .movable(true)
+            .collapsible(false)
+            .scroll([false, true])
+            .open(&mut is_window_open)
+            .resizable(true)
+            .show(ctx, |ui| {
+                let totals = self.history.totals_by_name();
+                let mut names: Vec<&String> = totals.keys().collect();
+                names.sort();
+
+                egui::Grid::new("history_grid")
+                    .num_columns(3)
+                    .striped(true)
+                    .show(ui, |ui_grid| {
+                        ui_grid.strong("Process");
+                        ui_grid.strong("Today");
+                        ui_grid.strong("All-time");
+                        ui_grid.end_row();
+
+                        for name in names {
+                            let (today_secs, total_secs) = totals[name];
+                            ui_grid.label(name.as_str());
+                            ui_grid.label(format_hm(today_secs));
+                            ui_grid.label(format_hm(total_secs));
+                            ui_grid.end_row();
+                        }
+                    });
+            });
+
+        self.history_window_open = is_window_open;
+    }
+
+    fn open_pomodoro_window(&mut self, ctx: &Context) {
+        let mut is_window_open = self.pomodoro_window_open;
+
+        egui::Window::new("Pomodoro")
+            .title_bar(true)
+            .movable(true)
+            .collapsible(false)
+            .open(&mut is_window_open)
+            .resizable(false)
+            .show(ctx, |ui| {
+                let running = self.pomodoro.is_some();
+
+                ui.add_enabled_ui(!running, |ui_config| {
+                    egui::Grid::new("pomodoro_config_grid")
+                        .num_columns(2)
+                        .show(ui_config, |ui_grid| {
+                            ui_grid.label("Work (min)");
+                            ui_grid.add(egui::DragValue::new(&mut self.pomodoro_work_minutes).range(1..=180));
+                            ui_grid.end_row();
+
+                            ui_grid.label("Short break (min)");
+                            ui_grid.add(egui::DragValue::new(&mut self.pomodoro_short_break_minutes).range(1..=60));
+                            ui_grid.end_row();
+
+                            ui_grid.label("Long break (min)");
+                            ui_grid.add(egui::DragValue::new(&mut self.pomodoro_long_break_minutes).range(1..=120));
+                            ui_grid.end_row();
+
+                            ui_grid.label("Cycles before long break");
+                            ui_grid.add(egui::DragValue::new(&mut self.pomodoro_cycles_before_long_break).range(1..=12));
+                            ui_grid.end_row();
+                        });
+                });
+
+                ui.separator();
+
+                if let Some(pomodoro) = &self.pomodoro {
+                    if let Ok(state) = pomodoro.state.read() {
+                        let secs = state.remaining.as_secs();
+                        ui.label(
+                            egui::RichText::new(format!(
+                                "{} - {:02}:{:02}",
+                                state.phase.label(),
+                                secs / 60,
+                                secs % 60
+                            ))
+                            .size(24f32)
+                            .monospace(),
+                        );
+                    }
+
+                    if ui.button("Stop Pomodoro").clicked() {
+                        if let Some(pomodoro) = self.pomodoro.take() {
+                            pomodoro.stop();
+                        }
+                    }
+                } else if ui.button("Start Pomodoro").clicked() {
+                    let config = PomodoroConfig {
+                        work: Duration::from_secs(u64::from(self.pomodoro_work_minutes) * 60),
+                        short_break: Duration::from_secs(
+                            u64::from(self.pomodoro_short_break_minutes) * 60,
+                        ),
+                        long_break: Duration::from_secs(
+                            u64::from(self.pomodoro_long_break_minutes) * 60,
+                        ),
+                        cycles_before_long_break: self.pomodoro_cycles_before_long_break,
+                    };
+                    self.pomodoro = Some(PomodoroHandle::spawn(
+                        config,
+                        self.paused_flags.clone(),
+                        ctx.clone(),
+                    ));
+                }
+            });
+
+        self.pomodoro_window_open = is_window_open;
+    }
+
+    fn open_resource_window(&mut self, ctx: &Context) {
+        let mut is_window_open = self.resource_window_open;
+
+        egui::Window::new("Resource Usage")
+            .title_bar(true)
+            .movable(true)
+            .collapsible(false)
+            .open(&mut is_window_open)
+            .resizable(true)
+            .default_size([420f32, 320f32])
+            .show(ctx, |ui| {
+                if self.tracked_processes.is_empty() {
+                    ui.label("No processes are being tracked.");
+                    return;
+                }
+
+                let selected_name = self
+                    .resource_chart_pid
+                    .and_then(|pid| self.tracked_processes.get(&pid))
+                    .map(|handle| handle.name.as_str())
+                    .unwrap_or("Select a process");
+
+                egui::ComboBox::from_label("Process")
+                    .selected_text(selected_name)
+                    .show_ui(ui, |ui_combo| {
+                        for (&pid, handle) in self.tracked_processes.iter() {
+                            ui_combo.selectable_value(
+                                &mut self.resource_chart_pid,
+                                Some(pid),
+                                handle.name.as_str(),
+                            );
+                        }
+                    });
+
+                let Some(pid) = self.resource_chart_pid else {
+                    return;
+                };
+                let Some(handle) = self.tracked_processes.get(&pid) else {
+                    self.resource_chart_pid = None;
+                    return;
+                };
+                let Ok(samples) = handle.samples.read() else {
+                    return;
+                };
+                if samples.is_empty() {
+                    ui.label("No samples yet.");
+                    return;
+                }
+
+                let mut peak_cpu_percent = 0f32;
+                let mut cpu_percent_sum = 0f32;
+                let mut peak_memory_bytes = 0u64;
+
+                let cpu_points: PlotPoints = samples
+                    .iter()
+                    .map(|sample| {
+                        peak_cpu_percent = peak_cpu_percent.max(sample.cpu_percent);
+                        cpu_percent_sum += sample.cpu_percent;
+                        peak_memory_bytes = peak_memory_bytes.max(sample.memory_bytes);
+                        [sample.elapsed_secs, sample.cpu_percent as f64]
+                    })
+                    .collect();
+                let avg_cpu_percent = cpu_percent_sum / samples.len() as f32;
+
+                let memory_points: PlotPoints = samples
+                    .iter()
+                    .map(|sample| {
+                        [
+                            sample.elapsed_secs,
+                            sample.memory_bytes as f64 / (1024.0 * 1024.0),
+                        ]
+                    })
+                    .collect();
+
+                ui.label(format!(
+                    "CPU avg {:.1}% / peak {:.1}%  \u{2022}  Peak memory {:.1} MB",
+                    avg_cpu_percent,
+                    peak_cpu_percent,
+                    peak_memory_bytes as f64 / (1024.0 * 1024.0)
+                ));
+
+                ui.label("CPU %");
+                Plot::new("cpu_usage_plot")
+                    .height(140f32)
+                    .show(ui, |plot_ui| {
+                        plot_ui.line(Line::new(cpu_points));
+                    });
+
+                ui.label("Memory (MB)");
+                Plot::new("memory_usage_plot")
+                    .height(140f32)
+                    .show(ui, |plot_ui| {
+                        plot_ui.line(Line::new(memory_points));
+                    });
+            });
+
+        self.resource_window_open = is_window_open;
+    }
+}
+
+/// Formats a duration in seconds as e.g. `4h12m`, for the History window's
+/// coarser totals (the live per-process timer keeps its `HH:MM:SS` display).
+fn format_hm(total_secs: u64) -> String {
+    let hours = total_secs / 3600;
+    let minutes = (total_secs % 3600) / 60;
+    format!("{}h{:02}m", hours, minutes)
 }
 
 impl eframe::App for ProcessApp {
     fn update(&mut self, ctx: &Context, _frame: &mut eframe::Frame) {
         egui::TopBottomPanel::top("Process").show(ctx, |ui| {
             ui.horizontal(|ui_hor| {
-                ui_hor.label(format!("Selected Process: {}", self.tracked_process_name));
+                ui_hor.label(format!("Tracking {} process(es)", self.tracked_processes.len()));
                 ui_hor.add_space(32f32);
-                if ui_hor.button("Select").clicked() {
+                if ui_hor.button("Add Process").clicked() {
                     self.system.refresh_processes(ProcessesToUpdate::All, true);
                     self.process_filter.clear();
                     self.filter_processes();
                     self.process_window_open = true;
                 }
 
-                let paused = self.paused.load(Ordering::Relaxed);
-                let paused_text = if paused { "Un-Pause" } else { "Pause" };
-                if ui_hor.button(paused_text).clicked() {
-                    self.paused.store(!paused, Ordering::Release);
+                if ui_hor.button("History").clicked() {
+                    self.history_window_open = true;
                 }
 
-                if ui_hor.button("Stop").clicked() {
-                    self.tracked_process = None;
-                    self.tracked_process_name = String::default();
-                    self.stop_and_join_thread();
-                    ctx.send_viewport_cmd(ViewportCommand::Title("Time Tracker".to_string()));
+                if ui_hor.button("Pomodoro").clicked() {
+                    self.pomodoro_window_open = true;
+                }
+
+                if ui_hor.button("Resources").clicked() {
+                    self.resource_window_open = true;
+                }
+
+                if let Some(pomodoro) = &self.pomodoro {
+                    if let Ok(state) = pomodoro.state.read() {
+                        let secs = state.remaining.as_secs();
+                        ui_hor.add_space(16f32);
+                        ui_hor.label(format!(
+                            "{}: {:02}:{:02}",
+                            state.phase.label(),
+                            secs / 60,
+                            secs % 60
+                        ));
+                    }
                 }
 
                 if self.process_window_open {
                     self.open_process_list_window(ctx);
                 }
+                if self.history_window_open {
+                    self.open_history_window(ctx);
+                }
+                if self.pomodoro_window_open {
+                    self.open_pomodoro_window(ctx);
+                }
+                if self.resource_window_open {
+                    self.open_resource_window(ctx);
+                }
             });
         });
         egui::CentralPanel::default().show(ctx, |ui| {
-            if let Ok(duration) = self.duration_text.read() {
-                ui.label(
-                    egui::RichText::new(duration.as_str())
-                        .size(48f32)
-                        .monospace(),
-                );
+            let mut stop_requested = Vec::new();
+            let mut finished = Vec::new();
+
+            egui::Grid::new("tracked_processes_grid")
+                .num_columns(4)
+                .striped(true)
+                .show(ui, |ui_grid| {
+                    for (&pid, handle) in self.tracked_processes.iter_mut() {
+                        ui_grid.label(handle.name.as_str());
+
+                        if let Ok(duration) = handle.duration_text.read() {
+                            ui_grid.label(
+                                egui::RichText::new(duration.as_str())
+                                    .size(24f32)
+                                    .monospace(),
+                            );
+                        }
+
+                        let paused = handle.paused.load(Ordering::Relaxed);
+                        let paused_text = if paused { "Un-Pause" } else { "Pause" };
+                        if ui_grid.button(paused_text).clicked() {
+                            handle.paused.store(!paused, Ordering::Release);
+                        }
+
+                        if ui_grid.button("Stop").clicked() {
+                            handle.stop();
+                            stop_requested.push(pid);
+                        }
+
+                        // The watcher reports its final duration once it stops,
+                        // whether that was requested or the process exited on its own.
+                        if let Ok(duration) = handle.result_rx.try_recv() {
+                            finished.push((pid, handle.name.clone(), handle.start, duration));
+                        }
+
+                        ui_grid.end_row();
+                    }
+                });
+
+            if !stop_requested.is_empty() || !finished.is_empty() {
+                ctx.request_repaint();
+            }
+
+            if !finished.is_empty() {
+                for (pid, name, start, duration) in finished {
+                    self.history
+                        .record_session(name, start, Utc::now(), duration.as_secs());
+                    self.tracked_processes.remove(&pid);
+                }
+                self.sync_paused_flags();
             }
         });
     }
 }
 
 fn main() {
+    let startup: StartupConfig = Cli::parse().into();
+
     let rt = Runtime::new().expect("Unable to create Runtime");
     let exit_process_token = CancellationToken::new();
     let exit_process_clone = exit_process_token.clone();
@@ -275,9 +837,9 @@ fn main() {
     let _result = eframe::run_native(
         "Time Tracker",
         native_options,
-        Box::new(|cc| Ok(Box::new(ProcessApp::new(cc)))),
+        Box::new(|cc| Ok(Box::new(ProcessApp::new(cc, startup)))),
     );
 
     exit_process_token.cancel();
     rt_thread.join().unwrap();
-}
\ No newline at end of file
+}
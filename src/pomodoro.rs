@@ -0,0 +1,215 @@
+use std::io::Cursor;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+
+use eframe::egui::Context;
+use rodio::{Decoder, OutputStream, Sink};
+use tokio::select;
+use tokio::time::{interval, Duration};
+use tokio_util::sync::CancellationToken;
+
+/// Bundled cue played on every phase transition.
+static NOTIFICATION_WAV: &[u8] = include_bytes!("../assets/notification.wav");
+
+/// User-configurable lengths for a classic work/break cycle.
+#[derive(Debug, Clone, Copy)]
+pub struct PomodoroConfig {
+    pub work: Duration,
+    pub short_break: Duration,
+    pub long_break: Duration,
+    pub cycles_before_long_break: u32,
+}
+
+impl Default for PomodoroConfig {
+    fn default() -> Self {
+        PomodoroConfig {
+            work: Duration::from_secs(25 * 60),
+            short_break: Duration::from_secs(5 * 60),
+            long_break: Duration::from_secs(15 * 60),
+            cycles_before_long_break: 4,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PomodoroPhase {
+    Work,
+    ShortBreak,
+    LongBreak,
+}
+
+impl PomodoroPhase {
+    pub fn label(self) -> &'static str {
+        match self {
+            PomodoroPhase::Work => "Work",
+            PomodoroPhase::ShortBreak => "Short Break",
+            PomodoroPhase::LongBreak => "Long Break",
+        }
+    }
+
+    pub fn is_break(self) -> bool {
+        !matches!(self, PomodoroPhase::Work)
+    }
+}
+
+/// Snapshot of the running cycle, read by the UI every frame.
+pub struct PomodoroState {
+    pub phase: PomodoroPhase,
+    pub remaining: Duration,
+}
+
+/// Drives the work/break cycle on a background task, pausing or resuming
+/// every tracked watcher as phases change.
+pub struct PomodoroHandle {
+    pub state: Arc<RwLock<PomodoroState>>,
+    cancellation_token: CancellationToken,
+}
+
+impl PomodoroHandle {
+    pub fn spawn(
+        config: PomodoroConfig,
+        tracked_paused_flags: Arc<RwLock<Vec<Arc<AtomicBool>>>>,
+        ctx: Context,
+    ) -> Self {
+        let state = Arc::new(RwLock::new(PomodoroState {
+            phase: PomodoroPhase::Work,
+            remaining: config.work,
+        }));
+        let cancellation_token = CancellationToken::new();
+
+        let task_state = state.clone();
+        let task_token = cancellation_token.clone();
+
+        tokio::spawn(async move {
+            pomodoro_cycle_async(config, task_state, tracked_paused_flags, task_token, ctx).await;
+        });
+
+        PomodoroHandle {
+            state,
+            cancellation_token,
+        }
+    }
+
+    pub fn stop(&self) {
+        self.cancellation_token.cancel();
+    }
+
+    /// Whether the cycle is currently in a short or long break, so a
+    /// freshly tracked process can be paused immediately instead of
+    /// waiting for the next phase transition.
+    pub fn is_break(&self) -> bool {
+        self.state
+            .read()
+            .map(|state| state.phase.is_break())
+            .unwrap_or(false)
+    }
+}
+
+/// Pauses every handle that isn't already paused, returning the ones Pomodoro
+/// itself paused so a later resume doesn't touch handles the user paused
+/// independently.
+fn pause_tracked(flags: &Arc<RwLock<Vec<Arc<AtomicBool>>>>) -> Vec<Arc<AtomicBool>> {
+    let Ok(flags) = flags.read() else {
+        return Vec::new();
+    };
+
+    flags
+        .iter()
+        .filter(|flag| !flag.swap(true, Ordering::AcqRel))
+        .cloned()
+        .collect()
+}
+
+/// Un-pauses only the handles Pomodoro previously paused, leaving any the
+/// user paused independently untouched.
+fn resume_tracked(paused_by_pomodoro: &mut Vec<Arc<AtomicBool>>) {
+    for flag in paused_by_pomodoro.drain(..) {
+        flag.store(false, Ordering::Release);
+    }
+}
+
+fn play_notification_sound() {
+    std::thread::spawn(|| {
+        let (_stream, stream_handle) = match OutputStream::try_default() {
+            Ok(stream) => stream,
+            Err(e) => {
+                eprintln!("Failed to open audio output: {}", e);
+                return;
+            }
+        };
+
+        let sink = match Sink::try_new(&stream_handle) {
+            Ok(sink) => sink,
+            Err(e) => {
+                eprintln!("Failed to create audio sink: {}", e);
+                return;
+            }
+        };
+
+        match Decoder::new(Cursor::new(NOTIFICATION_WAV)) {
+            Ok(source) => {
+                sink.append(source);
+                sink.sleep_until_end();
+            }
+            Err(e) => eprintln!("Failed to decode notification sound: {}", e),
+        }
+    });
+}
+
+async fn pomodoro_cycle_async(
+    config: PomodoroConfig,
+    state: Arc<RwLock<PomodoroState>>,
+    tracked_paused_flags: Arc<RwLock<Vec<Arc<AtomicBool>>>>,
+    cancellation_token: CancellationToken,
+    ctx: Context,
+) {
+    let mut phase = PomodoroPhase::Work;
+    let mut remaining = config.work;
+    let mut completed_work_cycles: u32 = 0;
+    let mut tick = interval(Duration::from_secs(1));
+    let mut paused_by_pomodoro: Vec<Arc<AtomicBool>> = Vec::new();
+
+    loop {
+        select! {
+            biased;
+            _ = cancellation_token.cancelled() => {
+                resume_tracked(&mut paused_by_pomodoro);
+                return;
+            },
+            _ = tick.tick() => {
+                if remaining > Duration::from_secs(1) {
+                    remaining -= Duration::from_secs(1);
+                } else {
+                    if phase == PomodoroPhase::Work {
+                        completed_work_cycles += 1;
+                        phase = if completed_work_cycles % config.cycles_before_long_break == 0 {
+                            PomodoroPhase::LongBreak
+                        } else {
+                            PomodoroPhase::ShortBreak
+                        };
+                    } else {
+                        phase = PomodoroPhase::Work;
+                    }
+                    remaining = match phase {
+                        PomodoroPhase::Work => config.work,
+                        PomodoroPhase::ShortBreak => config.short_break,
+                        PomodoroPhase::LongBreak => config.long_break,
+                    };
+
+                    if phase.is_break() {
+                        paused_by_pomodoro = pause_tracked(&tracked_paused_flags);
+                    } else {
+                        resume_tracked(&mut paused_by_pomodoro);
+                    }
+                    play_notification_sound();
+                }
+
+                if let Ok(mut state) = state.write() {
+                    state.phase = phase;
+                    state.remaining = remaining;
+                }
+                ctx.request_repaint();
+            }
+        }
+    }
+}